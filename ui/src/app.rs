@@ -1,26 +1,93 @@
 use eframe::egui;
+use serde::{Deserialize, Serialize};
 
+use crate::assets::Assets;
 use crate::backend::{BackendClient, ConnectionStatus};
-use crate::theme;
+use crate::crash;
+use crate::theme::{self, Palette, PaletteKind};
 use crate::widgets;
+use crate::widgets::feature_graph::GraphLayout;
+
+/// Which layout the feature-tree panel draws: the original indented list,
+/// or the force-directed dependency graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum FeatureView {
+    #[default]
+    List,
+    Graph,
+}
+
+const DEFAULT_BACKEND_URL: &str = "ws://127.0.0.1:9100";
+const SETTINGS_KEY: &str = "solidworks-voice-ai-settings";
+
+/// Persisted across restarts via `eframe`'s storage.
+#[derive(Serialize, Deserialize)]
+struct Settings {
+    backend_url: String,
+    palette: PaletteKind,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            backend_url: DEFAULT_BACKEND_URL.to_string(),
+            palette: PaletteKind::default(),
+        }
+    }
+}
 
 /// Top-level application state.
 pub struct VoiceAiApp {
     backend: BackendClient,
+    assets: Assets,
+    palette: Palette,
     command_input: String,
     show_settings: bool,
     backend_url: String,
+    feature_view: FeatureView,
+    graph_layout: GraphLayout,
+    recovered_crash: Option<String>,
 }
 
 impl VoiceAiApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        theme::apply_dark_theme(&cc.egui_ctx);
+        let settings: Settings = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, SETTINGS_KEY))
+            .unwrap_or_default();
+
+        let palette = settings.palette.palette();
+        theme::apply_theme(&cc.egui_ctx, &palette);
 
         Self {
-            backend: BackendClient::new("ws://127.0.0.1:9100"),
+            backend: BackendClient::new(&settings.backend_url, cc.egui_ctx.clone()),
+            assets: Assets::load(&cc.egui_ctx),
+            palette,
             command_input: String::new(),
             show_settings: false,
-            backend_url: "ws://127.0.0.1:9100".to_string(),
+            backend_url: settings.backend_url,
+            feature_view: FeatureView::default(),
+            graph_layout: GraphLayout::new(),
+            recovered_crash: crash::take_last_report(),
+        }
+    }
+
+    /// A toolbar button with a tinted icon (when the asset rasterized) and a
+    /// text label, falling back to a plain text button otherwise.
+    fn icon_button(
+        ui: &mut egui::Ui,
+        icon: Option<&egui::TextureHandle>,
+        tint: egui::Color32,
+        text: &str,
+    ) -> egui::Response {
+        match icon {
+            Some(texture) => {
+                let image = egui::Image::new(texture)
+                    .tint(tint)
+                    .fit_to_exact_size(egui::vec2(14.0, 14.0));
+                ui.add(egui::Button::image_and_text(image, text))
+            }
+            None => ui.button(text),
         }
     }
 }
@@ -28,15 +95,42 @@ impl VoiceAiApp {
 impl eframe::App for VoiceAiApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.backend.poll();
+        crash::update_snapshot(&self.backend);
+
+        // ── Recovered-from-crash panel ───────────────────────────
+        if let Some(report) = &self.recovered_crash {
+            let mut open = true;
+            egui::Window::new("Recovered from a crash")
+                .collapsible(false)
+                .open(&mut open)
+                .show(ctx, |ui| {
+                    ui.label("The app didn't shut down cleanly last time. Crash report:");
+                    egui::ScrollArea::vertical()
+                        .max_height(300.0)
+                        .show(ui, |ui| {
+                            ui.label(egui::RichText::new(report.as_str()).monospace().size(11.0));
+                        });
+                    ui.separator();
+                    if ui.button("Dismiss").clicked() {
+                        open = false;
+                    }
+                });
+            if !open {
+                self.recovered_crash = None;
+            }
+        }
 
         // ── Top bar ──────────────────────────────────────────────
         egui::TopBottomPanel::top("top_bar").show(ctx, |ui| {
             ui.horizontal(|ui| {
                 ui.heading("SolidWorks Voice AI");
                 ui.separator();
-                widgets::status_bar::render(ui, &self.backend);
+                widgets::status_bar::render(ui, &self.backend, &self.palette);
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if ui.button("Settings").clicked() {
+                    let settings_icon = self.assets.icon("settings");
+                    if Self::icon_button(ui, settings_icon, self.palette.text_primary, "Settings")
+                        .clicked()
+                    {
                         self.show_settings = !self.show_settings;
                     }
                 });
@@ -62,7 +156,12 @@ impl eframe::App for VoiceAiApp {
                             self.command_input.clear();
                         }
                     }
-                    if ui.button("Mic").clicked() {
+                    let mic_tint = if self.backend.is_listening() {
+                        self.palette.accent_red
+                    } else {
+                        self.palette.accent_blue
+                    };
+                    if Self::icon_button(ui, self.assets.icon("mic"), mic_tint, "Mic").clicked() {
                         self.backend.toggle_listening();
                     }
                 });
@@ -78,8 +177,26 @@ impl eframe::App for VoiceAiApp {
                     ui.text_edit_singleline(&mut self.backend_url);
                     ui.separator();
                     if ui.button("Reconnect").clicked() {
-                        self.backend = BackendClient::new(&self.backend_url);
+                        self.backend = BackendClient::new(&self.backend_url, ctx.clone());
                     }
+
+                    ui.separator();
+                    ui.label("Theme:");
+                    egui::ComboBox::from_id_source("palette_picker")
+                        .selected_text(self.palette.kind.label())
+                        .show_ui(ui, |ui| {
+                            for kind in PaletteKind::ALL {
+                                if ui
+                                    .selectable_label(self.palette.kind == kind, kind.label())
+                                    .clicked()
+                                {
+                                    self.palette = kind.palette();
+                                    theme::apply_theme(ctx, &self.palette);
+                                }
+                            }
+                        });
+
+                    ui.separator();
                     if ui.button("Close").clicked() {
                         self.show_settings = false;
                     }
@@ -91,25 +208,62 @@ impl eframe::App for VoiceAiApp {
             .default_width(260.0)
             .resizable(true)
             .show(ctx, |ui| {
-                ui.heading("Feature Tree");
+                ui.horizontal(|ui| {
+                    ui.heading("Feature Tree");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .selectable_label(self.feature_view == FeatureView::Graph, "Graph")
+                            .clicked()
+                        {
+                            self.feature_view = FeatureView::Graph;
+                        }
+                        if ui
+                            .selectable_label(self.feature_view == FeatureView::List, "List")
+                            .clicked()
+                        {
+                            self.feature_view = FeatureView::List;
+                        }
+                    });
+                });
                 ui.separator();
-                widgets::feature_tree::render(ui, &self.backend);
+                match self.feature_view {
+                    FeatureView::List => {
+                        widgets::feature_tree::render(ui, &self.backend, &self.assets, &self.palette)
+                    }
+                    FeatureView::Graph => widgets::feature_graph::render(
+                        ui,
+                        &self.backend,
+                        &self.palette,
+                        &mut self.graph_layout,
+                    ),
+                }
             });
 
         // ── Central area ────────────────────────────────────────
         egui::CentralPanel::default().show(ctx, |ui| {
             // Voice waveform at top
-            widgets::voice_panel::render(ui, &self.backend);
+            widgets::voice_panel::render(ui, &self.backend, &self.palette);
             ui.separator();
 
             // Command history fills the rest
             ui.heading("Command History");
-            widgets::history::render(ui, &self.backend);
+            widgets::history::render(ui, &self.backend, &self.palette);
         });
 
+        // ── Toasts (drawn last, on top of everything else) ─────
+        self.backend.notifications.render(ctx, &self.palette);
+
         // Repaint continuously while listening or when backend pushes updates
         if self.backend.is_listening() {
             ctx.request_repaint();
         }
     }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        let settings = Settings {
+            backend_url: self.backend_url.clone(),
+            palette: self.palette.kind,
+        };
+        eframe::set_value(storage, SETTINGS_KEY, &settings);
+    }
 }
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use eframe::egui;
+
+/// SVG sources embedded at compile time, keyed by the name widgets ask for.
+/// One entry per feature type plus the mic/settings glyphs used in the top
+/// and bottom bars. Icons are drawn white-on-transparent and tinted per
+/// feature type at render time via `egui::Image::tint`.
+const ICON_SOURCES: &[(&str, &str)] = &[
+    ("sketch", include_str!("../assets/icons/sketch.svg")),
+    ("extrude", include_str!("../assets/icons/extrude.svg")),
+    ("fillet", include_str!("../assets/icons/fillet.svg")),
+    ("chamfer", include_str!("../assets/icons/chamfer.svg")),
+    ("mirror", include_str!("../assets/icons/mirror.svg")),
+    (
+        "linear_pattern",
+        include_str!("../assets/icons/linear_pattern.svg"),
+    ),
+    ("export", include_str!("../assets/icons/export.svg")),
+    ("mic", include_str!("../assets/icons/mic.svg")),
+    ("settings", include_str!("../assets/icons/settings.svg")),
+];
+
+/// Icons are rasterized square at this point size before being oversampled
+/// for the display scale; egui then draws them back down to a text line's
+/// height, so they stay crisp on HiDPI screens.
+const ICON_POINT_SIZE: f32 = 16.0;
+
+/// Icon textures rasterized once at startup from the bundled SVGs in
+/// `ICON_SOURCES`.
+pub struct Assets {
+    icons: HashMap<&'static str, egui::TextureHandle>,
+}
+
+impl Assets {
+    /// Parse every bundled SVG with `usvg` and rasterize it with
+    /// `tiny-skia`, oversampling by `pixels_per_point() * 2` so the result
+    /// stays crisp when egui scales it back down to text-line height.
+    pub fn load(ctx: &egui::Context) -> Self {
+        let oversample = ctx.pixels_per_point() * 2.0;
+        let px = (ICON_POINT_SIZE * oversample).round().max(1.0) as u32;
+
+        let mut icons = HashMap::with_capacity(ICON_SOURCES.len());
+        for (name, source) in ICON_SOURCES {
+            match rasterize(source, px) {
+                Ok(image) => {
+                    let texture = ctx.load_texture(*name, image, egui::TextureOptions::LINEAR);
+                    icons.insert(*name, texture);
+                }
+                Err(err) => tracing::warn!("failed to rasterize icon '{name}': {err}"),
+            }
+        }
+
+        Self { icons }
+    }
+
+    /// The texture for a feature type or glyph name, if it rasterized
+    /// successfully at startup.
+    pub fn icon(&self, name: &str) -> Option<&egui::TextureHandle> {
+        self.icons.get(name)
+    }
+}
+
+fn rasterize(source: &str, px: u32) -> Result<egui::ColorImage, String> {
+    let tree =
+        usvg::Tree::from_str(source, &usvg::Options::default()).map_err(|err| err.to_string())?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(px, px).ok_or("zero-sized icon pixmap")?;
+    let view_box = tree.size();
+    let scale = px as f32 / view_box.width().max(view_box.height());
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    // `tiny_skia::Pixmap` only ever stores premultiplied-alpha pixels, so
+    // feeding its bytes to `from_rgba_unmultiplied` would have egui
+    // premultiply them a second time — every anti-aliased edge and the
+    // translucent half of `mirror.svg` would come out too dark. Build the
+    // `Color32`s directly from the premultiplied channels instead.
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .map(|p| egui::Color32::from_rgba_premultiplied(p.red(), p.green(), p.blue(), p.alpha()))
+        .collect();
+
+    Ok(egui::ColorImage {
+        size: [px as usize, px as usize],
+        pixels,
+    })
+}
@@ -1,6 +1,10 @@
+use eframe::egui;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+use crate::notifications::Notifications;
+use crate::transport::{Transport, TransportEvent};
+
 // ─── Data types ──────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +23,15 @@ pub struct FeatureEntry {
     pub parameters: serde_json::Value,
     #[serde(default)]
     pub timestamp: String,
+    /// Label of the feature this one was created on top of, e.g. the
+    /// sketch an extrude was built from. Drives the parent/child edges in
+    /// the force-directed feature graph view.
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// Labels of other features this one references without being a
+    /// direct child, e.g. the source body a mirror or pattern repeats.
+    #[serde(default)]
+    pub references: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,6 +83,8 @@ pub struct BackendClient {
     url: String,
     status: ConnectionStatus,
     listening: bool,
+    transport: Transport,
+    pub notifications: Notifications,
 
     // Service connectivity
     pub solidworks_ok: bool,
@@ -85,11 +100,16 @@ pub struct BackendClient {
 }
 
 impl BackendClient {
-    pub fn new(url: &str) -> Self {
+    /// Connect to `url`, spawning a background thread that owns the
+    /// WebSocket and reconnects on its own with exponential backoff. `ctx`
+    /// lets that thread wake the UI when a frame arrives.
+    pub fn new(url: &str, ctx: egui::Context) -> Self {
         Self {
             url: url.to_string(),
             status: ConnectionStatus::Disconnected,
             listening: false,
+            transport: Transport::spawn(url, ctx),
+            notifications: Notifications::new(),
             solidworks_ok: false,
             qdrant_ok: false,
             ollama_ok: false,
@@ -101,11 +121,34 @@ impl BackendClient {
         }
     }
 
-    /// Poll for new messages from the backend (non-blocking).
-    /// In production this reads from the WebSocket; here we maintain state.
+    /// Drain messages the background transport thread has queued up since
+    /// the last frame (non-blocking).
     pub fn poll(&mut self) {
-        // TODO: integrate real WebSocket I/O via tungstenite on a background thread.
-        // For now the app renders with local state.
+        for event in self.transport.try_recv() {
+            match event {
+                TransportEvent::Connecting => {
+                    self.status = ConnectionStatus::Connecting;
+                }
+                TransportEvent::Connected => {
+                    self.status = ConnectionStatus::Connected;
+                    self.notifications
+                        .ok("Connected", format!("Connected to {}", self.url));
+                }
+                TransportEvent::Disconnected => {
+                    let was_connected = self.status == ConnectionStatus::Connected;
+                    self.status = ConnectionStatus::Disconnected;
+                    self.solidworks_ok = false;
+                    self.qdrant_ok = false;
+                    self.ollama_ok = false;
+                    self.claude_ok = false;
+                    if was_connected {
+                        self.notifications
+                            .warn("Disconnected", "Lost connection to backend, retrying…");
+                    }
+                }
+                TransportEvent::Message(msg) => self.handle_message(msg),
+            }
+        }
     }
 
     pub fn connection_status(&self) -> ConnectionStatus {
@@ -125,14 +168,18 @@ impl BackendClient {
         };
         self.history.push_front(entry);
 
-        // TODO: serialize ClientMessage::Command and send over WebSocket
         tracing::info!("send_command: {text}");
+        self.transport.send(ClientMessage::Command {
+            text: text.to_string(),
+        });
     }
 
     pub fn toggle_listening(&mut self) {
         self.listening = !self.listening;
         tracing::info!("listening = {}", self.listening);
-        // TODO: send ClientMessage::Listen over WebSocket
+        self.transport.send(ClientMessage::Listen {
+            start: self.listening,
+        });
     }
 
     /// Apply a message received from the Python backend.
@@ -161,6 +208,9 @@ impl BackendClient {
                 action,
                 result,
             } => {
+                if action == "error" {
+                    self.notifications.error(&command, &result);
+                }
                 let entry = HistoryEntry {
                     timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
                     command,
@@ -170,6 +220,8 @@ impl BackendClient {
                 self.history.push_front(entry);
             }
             ServerMessage::Features { items } => {
+                self.notifications
+                    .info("Feature tree updated", format!("{} feature(s)", items.len()));
                 self.features = items;
             }
             ServerMessage::Waveform { samples } => {
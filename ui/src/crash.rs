@@ -0,0 +1,138 @@
+use std::fs;
+use std::panic::{self, PanicHookInfo};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use crate::backend::BackendClient;
+
+/// A point-in-time view of app state cheap enough to refresh every frame,
+/// so a crash report can include what the user was doing right before the
+/// panic instead of just the panic message.
+#[derive(Default, Clone)]
+struct Snapshot {
+    connection_status: String,
+    solidworks_ok: bool,
+    qdrant_ok: bool,
+    ollama_ok: bool,
+    claude_ok: bool,
+    recent_commands: Vec<String>,
+}
+
+static SNAPSHOT: OnceLock<Mutex<Snapshot>> = OnceLock::new();
+
+/// Refresh the snapshot the panic hook will embed in a crash report. Call
+/// once per frame; cheap enough not to matter.
+pub fn update_snapshot(backend: &BackendClient) {
+    let snapshot = Snapshot {
+        connection_status: format!("{:?}", backend.connection_status()),
+        solidworks_ok: backend.solidworks_ok,
+        qdrant_ok: backend.qdrant_ok,
+        ollama_ok: backend.ollama_ok,
+        claude_ok: backend.claude_ok,
+        recent_commands: backend
+            .history
+            .iter()
+            .take(5)
+            .map(|h| format!("[{}] {} → {} ({})", h.timestamp, h.command, h.action, h.result))
+            .collect(),
+    };
+    let cell = SNAPSHOT.get_or_init(|| Mutex::new(Snapshot::default()));
+    if let Ok(mut guard) = cell.lock() {
+        *guard = snapshot;
+    }
+}
+
+/// Install a panic hook that still runs the default hook (so `RUST_BACKTRACE`
+/// output etc. keeps working) but first writes a timestamped crash report
+/// next to the executable. A windowed egui app otherwise leaves the user
+/// with nothing when the UI thread or the backend transport thread panics,
+/// the way a terminal app would at least print to the shell it was launched
+/// from.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if let Err(err) = write_report(info) {
+            tracing::error!("failed to write crash report: {err}");
+        }
+        default_hook(info);
+    }));
+}
+
+fn crash_dir() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| path.parent().map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+fn payload_str(info: &PanicHookInfo<'_>) -> String {
+    if let Some(s) = info.payload().downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = info.payload().downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "<non-string panic payload>".to_string()
+    }
+}
+
+fn write_report(info: &PanicHookInfo<'_>) -> std::io::Result<()> {
+    let now = chrono::Local::now();
+    let location = info
+        .location()
+        .map(|l| l.to_string())
+        .unwrap_or_else(|| "unknown location".to_string());
+    let backtrace = std::backtrace::Backtrace::force_capture();
+
+    let snapshot = SNAPSHOT
+        .get()
+        .and_then(|cell| cell.lock().ok().map(|g| g.clone()))
+        .unwrap_or_default();
+
+    let mut report = String::new();
+    report.push_str("SolidWorks Voice AI crash report\n");
+    report.push_str(&format!("time: {}\n", now.format("%Y-%m-%d %H:%M:%S")));
+    report.push_str(&format!("location: {location}\n"));
+    report.push_str(&format!("panic: {}\n", payload_str(info)));
+    report.push_str(&format!("connection status: {}\n", snapshot.connection_status));
+    report.push_str(&format!(
+        "services: solidworks={} qdrant={} ollama={} claude={}\n",
+        snapshot.solidworks_ok, snapshot.qdrant_ok, snapshot.ollama_ok, snapshot.claude_ok
+    ));
+    report.push_str("recent commands:\n");
+    if snapshot.recent_commands.is_empty() {
+        report.push_str("  (none)\n");
+    } else {
+        for command in &snapshot.recent_commands {
+            report.push_str(&format!("  {command}\n"));
+        }
+    }
+    report.push_str("\nbacktrace:\n");
+    report.push_str(&backtrace.to_string());
+    report.push('\n');
+
+    let path = crash_dir().join(format!("crash_{}.log", now.format("%Y%m%d_%H%M%S")));
+    fs::write(path, report)
+}
+
+/// If a crash report is sitting next to the executable from a previous run,
+/// read and remove it, returning its contents so the app can surface a
+/// dismissible "Recovered from a crash" panel.
+pub fn take_last_report() -> Option<String> {
+    let dir = crash_dir();
+    let mut entries: Vec<_> = fs::read_dir(&dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("crash_")
+        })
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let latest = entries.pop()?;
+    let contents = fs::read_to_string(latest.path()).ok()?;
+    let _ = fs::remove_file(latest.path());
+    Some(contents)
+}
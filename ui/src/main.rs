@@ -1,6 +1,10 @@
 mod app;
+mod assets;
 mod backend;
+mod crash;
+mod notifications;
 mod theme;
+mod transport;
 mod widgets;
 
 use app::VoiceAiApp;
@@ -10,6 +14,8 @@ fn main() -> eframe::Result<()> {
         .with_env_filter("solidworks_voice_ai=debug,info")
         .init();
 
+    crash::install_panic_hook();
+
     let native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_title("SolidWorks Voice AI")
@@ -0,0 +1,155 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+
+use crate::theme::Palette;
+
+/// How serious a toast is, which drives its accent colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Ok,
+    Warn,
+    Error,
+}
+
+impl Severity {
+    fn colour(self, palette: &Palette) -> egui::Color32 {
+        match self {
+            Severity::Info => palette.accent_blue,
+            Severity::Ok => palette.status_ok(),
+            Severity::Warn => palette.status_warn(),
+            Severity::Error => palette.status_err(),
+        }
+    }
+}
+
+const LIFETIME: Duration = Duration::from_secs(5);
+const FADE: Duration = Duration::from_millis(300);
+const TOAST_HEIGHT: f32 = 72.0;
+
+struct Toast {
+    severity: Severity,
+    title: String,
+    body: String,
+    spawned_at: Instant,
+}
+
+impl Toast {
+    fn age(&self) -> Duration {
+        self.spawned_at.elapsed()
+    }
+
+    fn expired(&self) -> bool {
+        self.age() >= LIFETIME
+    }
+
+    /// 1.0 while fresh, ramping down to 0.0 over the last `FADE` before expiry.
+    fn opacity(&self) -> f32 {
+        let remaining = LIFETIME.saturating_sub(self.age());
+        if remaining >= FADE {
+            1.0
+        } else {
+            remaining.as_secs_f32() / FADE.as_secs_f32()
+        }
+    }
+}
+
+/// A stack of transient toast notifications rendered in a viewport corner,
+/// modeled on a notification-daemon: each has a severity, a title, a body,
+/// and fades out after a timeout. Clicking a toast dismisses it early.
+pub struct Notifications {
+    toasts: VecDeque<Toast>,
+}
+
+impl Notifications {
+    pub fn new() -> Self {
+        Self {
+            toasts: VecDeque::new(),
+        }
+    }
+
+    pub fn push(&mut self, severity: Severity, title: impl Into<String>, body: impl Into<String>) {
+        self.toasts.push_back(Toast {
+            severity,
+            title: title.into(),
+            body: body.into(),
+            spawned_at: Instant::now(),
+        });
+    }
+
+    pub fn info(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.push(Severity::Info, title, body);
+    }
+
+    pub fn ok(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.push(Severity::Ok, title, body);
+    }
+
+    pub fn warn(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.push(Severity::Warn, title, body);
+    }
+
+    pub fn error(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.push(Severity::Error, title, body);
+    }
+
+    /// Draw the stack, oldest at the bottom and newest on top, anchored to the bottom-right of
+    /// the viewport. Drops expired toasts and any the user clicked away.
+    pub fn render(&mut self, ctx: &egui::Context, palette: &Palette) {
+        self.toasts.retain(|t| !t.expired());
+
+        let mut dismissed = None;
+        for (i, toast) in self.toasts.iter().enumerate() {
+            let opacity = toast.opacity();
+            let accent = toast.severity.colour(palette);
+
+            egui::Area::new(egui::Id::new("toast").with(i))
+                .anchor(
+                    egui::Align2::RIGHT_BOTTOM,
+                    egui::vec2(-12.0, -12.0 - i as f32 * TOAST_HEIGHT),
+                )
+                .order(egui::Order::Foreground)
+                .show(ctx, |ui| {
+                    let frame = egui::Frame::popup(ui.style())
+                        .fill(palette.window_fill.linear_multiply(opacity))
+                        .stroke(egui::Stroke::new(1.0, accent.linear_multiply(opacity)));
+
+                    let inner = frame.show(ui, |ui| {
+                        ui.set_width(260.0);
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("●").color(accent.linear_multiply(opacity)));
+                            ui.label(
+                                egui::RichText::new(&toast.title)
+                                    .strong()
+                                    .color(palette.text_strong.linear_multiply(opacity)),
+                            );
+                        });
+                        if !toast.body.is_empty() {
+                            ui.label(
+                                egui::RichText::new(&toast.body)
+                                    .color(palette.text_primary.linear_multiply(opacity))
+                                    .size(12.0),
+                            );
+                        }
+                    });
+
+                    let response =
+                        ui.interact(inner.response.rect, ui.id().with(("dismiss", i)), egui::Sense::click());
+                    if response.clicked() {
+                        dismissed = Some(i);
+                    }
+                });
+        }
+
+        if let Some(i) = dismissed {
+            self.toasts.remove(i);
+        }
+
+        // Keep repainting while any toast is shown so it fades and expires on time.
+        if !self.toasts.is_empty() {
+            ctx.request_repaint();
+        }
+    }
+}
@@ -1,42 +1,183 @@
 use egui::{Color32, FontFamily, FontId, Rounding, Stroke, Style, TextStyle, Visuals};
+use serde::{Deserialize, Serialize};
+
+/// Which bundled `Palette` is active. Persisted to disk alongside the
+/// backend URL so the choice survives restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaletteKind {
+    Dark,
+    Light,
+    HighContrast,
+}
 
-/// Apply a professional dark theme tuned for engineering / CAD workflows.
-pub fn apply_dark_theme(ctx: &egui::Context) {
-    let mut style = Style::default();
+impl Default for PaletteKind {
+    fn default() -> Self {
+        PaletteKind::Dark
+    }
+}
 
-    // ── Colours ──────────────────────────────────────────────
-    let mut visuals = Visuals::dark();
+impl PaletteKind {
+    pub const ALL: [PaletteKind; 3] = [PaletteKind::Dark, PaletteKind::Light, PaletteKind::HighContrast];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PaletteKind::Dark => "Dark",
+            PaletteKind::Light => "Light",
+            PaletteKind::HighContrast => "High Contrast",
+        }
+    }
+
+    pub fn palette(self) -> Palette {
+        match self {
+            PaletteKind::Dark => Palette::dark(),
+            PaletteKind::Light => Palette::light(),
+            PaletteKind::HighContrast => Palette::high_contrast(),
+        }
+    }
+}
+
+/// A named set of colours widgets draw themselves with. Replaces the old
+/// fixed `ACCENT_*`/`STATUS_*` constants so the app can switch appearance
+/// at runtime instead of baking in a single dark theme.
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub kind: PaletteKind,
+
+    // Chrome
+    pub panel_fill: Color32,
+    pub window_fill: Color32,
+    pub extreme_bg: Color32,
+    pub faint_bg: Color32,
+
+    // Text
+    pub text_strong: Color32,
+    pub text_primary: Color32,
+    pub text_secondary: Color32,
+    pub text_muted: Color32,
+
+    // Accents
+    pub accent_blue: Color32,
+    pub accent_green: Color32,
+    pub accent_red: Color32,
+    pub accent_amber: Color32,
+    pub accent_purple: Color32,
+}
 
-    // Background
-    visuals.panel_fill = Color32::from_rgb(18, 18, 24);
-    visuals.window_fill = Color32::from_rgb(24, 24, 32);
-    visuals.extreme_bg_color = Color32::from_rgb(12, 12, 16);
-    visuals.faint_bg_color = Color32::from_rgb(30, 30, 40);
+impl Palette {
+    /// Professional dark theme tuned for engineering / CAD workflows.
+    pub fn dark() -> Self {
+        Self {
+            kind: PaletteKind::Dark,
+            panel_fill: Color32::from_rgb(18, 18, 24),
+            window_fill: Color32::from_rgb(24, 24, 32),
+            extreme_bg: Color32::from_rgb(12, 12, 16),
+            faint_bg: Color32::from_rgb(30, 30, 40),
+            text_strong: Color32::WHITE,
+            text_primary: Color32::from_rgb(210, 210, 225),
+            text_secondary: Color32::from_rgb(150, 150, 165),
+            text_muted: Color32::from_rgb(100, 100, 115),
+            accent_blue: Color32::from_rgb(80, 140, 255),
+            accent_green: Color32::from_rgb(50, 205, 100),
+            accent_red: Color32::from_rgb(230, 60, 60),
+            accent_amber: Color32::from_rgb(245, 170, 50),
+            accent_purple: Color32::from_rgb(150, 100, 240),
+        }
+    }
+
+    /// Light counterpart for bright CAD workstation environments.
+    pub fn light() -> Self {
+        Self {
+            kind: PaletteKind::Light,
+            panel_fill: Color32::from_rgb(246, 246, 249),
+            window_fill: Color32::from_rgb(255, 255, 255),
+            extreme_bg: Color32::from_rgb(232, 232, 237),
+            faint_bg: Color32::from_rgb(225, 225, 232),
+            text_strong: Color32::from_rgb(20, 20, 26),
+            text_primary: Color32::from_rgb(40, 40, 50),
+            text_secondary: Color32::from_rgb(90, 90, 105),
+            text_muted: Color32::from_rgb(140, 140, 150),
+            accent_blue: Color32::from_rgb(30, 95, 220),
+            accent_green: Color32::from_rgb(20, 140, 70),
+            accent_red: Color32::from_rgb(195, 35, 35),
+            accent_amber: Color32::from_rgb(195, 125, 10),
+            accent_purple: Color32::from_rgb(110, 60, 200),
+        }
+    }
+
+    /// High-contrast variant for accessibility: near-black/white chrome and
+    /// saturated, widely-spaced accents.
+    pub fn high_contrast() -> Self {
+        Self {
+            kind: PaletteKind::HighContrast,
+            panel_fill: Color32::BLACK,
+            window_fill: Color32::from_rgb(8, 8, 8),
+            extreme_bg: Color32::BLACK,
+            faint_bg: Color32::from_rgb(20, 20, 20),
+            text_strong: Color32::WHITE,
+            text_primary: Color32::WHITE,
+            text_secondary: Color32::from_rgb(225, 225, 225),
+            text_muted: Color32::from_rgb(170, 170, 170),
+            accent_blue: Color32::from_rgb(110, 170, 255),
+            accent_green: Color32::from_rgb(60, 255, 120),
+            accent_red: Color32::from_rgb(255, 70, 70),
+            accent_amber: Color32::from_rgb(255, 200, 40),
+            accent_purple: Color32::from_rgb(190, 130, 255),
+        }
+    }
+
+    pub fn status_ok(&self) -> Color32 {
+        self.accent_green
+    }
+
+    pub fn status_warn(&self) -> Color32 {
+        self.accent_amber
+    }
+
+    pub fn status_err(&self) -> Color32 {
+        self.accent_red
+    }
+}
+
+/// Apply `palette` to the egui style: colours, plus the typography and
+/// spacing shared by every palette.
+pub fn apply_theme(ctx: &egui::Context, palette: &Palette) {
+    let mut style = Style::default();
 
-    // Widgets
-    visuals.widgets.noninteractive.bg_fill = Color32::from_rgb(30, 30, 40);
-    visuals.widgets.noninteractive.fg_stroke = Stroke::new(1.0, Color32::from_rgb(180, 180, 195));
+    // ── Colours ──────────────────────────────────────────────
+    let mut visuals = if matches!(palette.kind, PaletteKind::Light) {
+        Visuals::light()
+    } else {
+        Visuals::dark()
+    };
+
+    visuals.panel_fill = palette.panel_fill;
+    visuals.window_fill = palette.window_fill;
+    visuals.extreme_bg_color = palette.extreme_bg;
+    visuals.faint_bg_color = palette.faint_bg;
+
+    visuals.widgets.noninteractive.bg_fill = palette.faint_bg;
+    visuals.widgets.noninteractive.fg_stroke = Stroke::new(1.0, palette.text_secondary);
     visuals.widgets.noninteractive.rounding = Rounding::same(6.0);
 
-    visuals.widgets.inactive.bg_fill = Color32::from_rgb(40, 40, 55);
-    visuals.widgets.inactive.fg_stroke = Stroke::new(1.0, Color32::from_rgb(200, 200, 215));
+    visuals.widgets.inactive.bg_fill = palette.faint_bg.gamma_multiply(1.3);
+    visuals.widgets.inactive.fg_stroke = Stroke::new(1.0, palette.text_primary);
     visuals.widgets.inactive.rounding = Rounding::same(6.0);
 
-    visuals.widgets.hovered.bg_fill = Color32::from_rgb(55, 55, 75);
-    visuals.widgets.hovered.fg_stroke = Stroke::new(1.5, Color32::WHITE);
+    visuals.widgets.hovered.bg_fill = palette.faint_bg.gamma_multiply(1.8);
+    visuals.widgets.hovered.fg_stroke = Stroke::new(1.5, palette.text_strong);
     visuals.widgets.hovered.rounding = Rounding::same(6.0);
 
-    visuals.widgets.active.bg_fill = Color32::from_rgb(70, 70, 95);
-    visuals.widgets.active.fg_stroke = Stroke::new(2.0, Color32::WHITE);
+    visuals.widgets.active.bg_fill = palette.faint_bg.gamma_multiply(2.3);
+    visuals.widgets.active.fg_stroke = Stroke::new(2.0, palette.text_strong);
     visuals.widgets.active.rounding = Rounding::same(6.0);
 
     // Selection
-    visuals.selection.bg_fill = Color32::from_rgb(60, 90, 180);
-    visuals.selection.stroke = Stroke::new(1.0, Color32::from_rgb(130, 170, 255));
+    visuals.selection.bg_fill = palette.accent_blue.gamma_multiply(0.7);
+    visuals.selection.stroke = Stroke::new(1.0, palette.accent_blue);
 
     // Separators & window
     visuals.window_rounding = Rounding::same(10.0);
-    visuals.window_stroke = Stroke::new(1.0, Color32::from_rgb(50, 50, 65));
+    visuals.window_stroke = Stroke::new(1.0, palette.faint_bg.gamma_multiply(1.7));
 
     style.visuals = visuals;
 
@@ -68,15 +209,3 @@ pub fn apply_dark_theme(ctx: &egui::Context) {
 
     ctx.set_style(style);
 }
-
-// ── Accent colours used by widgets ───────────────────────────────────────────
-
-pub const ACCENT_BLUE: Color32 = Color32::from_rgb(80, 140, 255);
-pub const ACCENT_GREEN: Color32 = Color32::from_rgb(50, 205, 100);
-pub const ACCENT_RED: Color32 = Color32::from_rgb(230, 60, 60);
-pub const ACCENT_AMBER: Color32 = Color32::from_rgb(245, 170, 50);
-pub const ACCENT_PURPLE: Color32 = Color32::from_rgb(150, 100, 240);
-
-pub const STATUS_OK: Color32 = ACCENT_GREEN;
-pub const STATUS_ERR: Color32 = ACCENT_RED;
-pub const STATUS_WARN: Color32 = ACCENT_AMBER;
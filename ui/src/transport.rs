@@ -0,0 +1,198 @@
+use std::io::ErrorKind;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use egui::Context;
+use tungstenite::client::IntoClientRequest;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message, WebSocket};
+
+use crate::backend::{ClientMessage, ServerMessage};
+
+/// Events emitted by the background transport thread as the connection's
+/// state machine (`Connecting` → `Connected` → `Disconnected`, repeat)
+/// advances, or as server frames arrive.
+pub enum TransportEvent {
+    Connecting,
+    Connected,
+    Disconnected,
+    Message(ServerMessage),
+}
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(16);
+const READ_POLL: Duration = Duration::from_millis(100);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Owns the background thread that speaks WebSocket to the Python backend.
+///
+/// Dropping a `Transport` closes the outbound channel, which is enough for
+/// the background thread to notice and exit on its next pass — this is what
+/// the Settings "Reconnect" button relies on to tear down the old socket
+/// before a fresh `Transport` is spawned against the new URL.
+pub struct Transport {
+    cmd_tx: mpsc::Sender<ClientMessage>,
+    evt_rx: mpsc::Receiver<TransportEvent>,
+    _handle: thread::JoinHandle<()>,
+}
+
+impl Transport {
+    /// Spawn a background thread that connects to `url`, reconnecting with
+    /// exponential backoff whenever the socket drops. `ctx` is used to wake
+    /// the UI whenever a frame arrives or the connection state changes,
+    /// since egui otherwise only repaints continuously while listening.
+    pub fn spawn(url: &str, ctx: Context) -> Self {
+        let (cmd_tx, cmd_rx) = mpsc::channel::<ClientMessage>();
+        let (evt_tx, evt_rx) = mpsc::channel::<TransportEvent>();
+        let url = url.to_string();
+
+        let handle = thread::Builder::new()
+            .name("backend-ws".into())
+            .spawn(move || run(&url, &ctx, &cmd_rx, &evt_tx))
+            .expect("failed to spawn backend-ws thread");
+
+        Self {
+            cmd_tx,
+            evt_rx,
+            _handle: handle,
+        }
+    }
+
+    /// Queue a command for the background thread to send. While the socket
+    /// is down it stays queued and goes out after the next successful
+    /// connect; it is silently dropped only if the thread has exited.
+    pub fn send(&self, msg: ClientMessage) {
+        let _ = self.cmd_tx.send(msg);
+    }
+
+    /// Drain every event that has arrived since the last poll.
+    pub fn try_recv(&self) -> Vec<TransportEvent> {
+        self.evt_rx.try_iter().collect()
+    }
+}
+
+fn run(
+    url: &str,
+    ctx: &Context,
+    cmd_rx: &mpsc::Receiver<ClientMessage>,
+    evt_tx: &mpsc::Sender<TransportEvent>,
+) {
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        // A closed event channel means the owning `Transport` was dropped,
+        // even if no connection ever succeeded; stop instead of retrying.
+        if evt_tx.send(TransportEvent::Connecting).is_err() {
+            return;
+        }
+        ctx.request_repaint();
+
+        match connect_with_timeout(url, CONNECT_TIMEOUT) {
+            Ok(mut socket) => {
+                tracing::info!("connected to {url}");
+                backoff = INITIAL_BACKOFF;
+                let _ = evt_tx.send(TransportEvent::Connected);
+                ctx.request_repaint();
+
+                if !pump(&mut socket, cmd_rx, evt_tx, ctx) {
+                    // Outbound channel closed: the owning `Transport` was
+                    // dropped (Reconnect / app shutdown), so stop for good.
+                    return;
+                }
+            }
+            Err(err) => {
+                tracing::warn!("connect to {url} failed: {err}");
+            }
+        }
+
+        let _ = evt_tx.send(TransportEvent::Disconnected);
+        ctx.request_repaint();
+        thread::sleep(backoff);
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Resolve `url`, connect the raw TCP stream with a bounded timeout, set its
+/// read timeout unconditionally, and only then hand it to `tungstenite` for
+/// the WebSocket/TLS handshake. `tungstenite::connect` alone blocks forever
+/// on an unreachable host and only ever sets a read timeout on the `Plain`
+/// branch, leaving `wss://` connections with none at all — both of which
+/// break `pump`'s expectation that a torn-down `Transport` unblocks promptly.
+fn connect_with_timeout(
+    url: &str,
+    timeout: Duration,
+) -> Result<WebSocket<MaybeTlsStream<TcpStream>>, String> {
+    let request = url.into_client_request().map_err(|err| err.to_string())?;
+    let host = request
+        .uri()
+        .host()
+        .ok_or_else(|| "url has no host".to_string())?
+        .to_string();
+    let port = request.uri().port_u16().unwrap_or(match request.uri().scheme_str() {
+        Some("wss") => 443,
+        _ => 80,
+    });
+
+    let addr = (host.as_str(), port)
+        .to_socket_addrs()
+        .map_err(|err| err.to_string())?
+        .next()
+        .ok_or_else(|| format!("could not resolve {host}:{port}"))?;
+
+    let tcp = TcpStream::connect_timeout(&addr, timeout).map_err(|err| err.to_string())?;
+    tcp.set_read_timeout(Some(READ_POLL))
+        .map_err(|err| err.to_string())?;
+
+    let (socket, _response) =
+        tungstenite::client_tls(request, tcp).map_err(|err| err.to_string())?;
+    Ok(socket)
+}
+
+/// Run one connected session: forward queued outbound commands and dispatch
+/// inbound frames until the socket drops or the caller tears down the
+/// transport. Returns `false` if the transport itself was torn down (the
+/// caller should stop reconnecting), `true` if only the socket dropped.
+fn pump(
+    socket: &mut WebSocket<MaybeTlsStream<TcpStream>>,
+    cmd_rx: &mpsc::Receiver<ClientMessage>,
+    evt_tx: &mpsc::Sender<TransportEvent>,
+    ctx: &Context,
+) -> bool {
+    loop {
+        loop {
+            match cmd_rx.try_recv() {
+                Ok(msg) => match serde_json::to_string(&msg) {
+                    Ok(text) => {
+                        if let Err(err) = socket.send(Message::Text(text)) {
+                            tracing::warn!("send failed: {err}");
+                            return true;
+                        }
+                    }
+                    Err(err) => tracing::warn!("failed to serialize {msg:?}: {err}"),
+                },
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => return false,
+            }
+        }
+
+        match socket.read() {
+            Ok(Message::Text(text)) => match serde_json::from_str::<ServerMessage>(&text) {
+                Ok(parsed) => {
+                    let _ = evt_tx.send(TransportEvent::Message(parsed));
+                    ctx.request_repaint();
+                }
+                Err(err) => tracing::warn!("bad frame from backend: {err}"),
+            },
+            Ok(Message::Close(_)) => return true,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(ref io))
+                if io.kind() == ErrorKind::WouldBlock || io.kind() == ErrorKind::TimedOut => {}
+            Err(err) => {
+                tracing::warn!("read failed: {err}");
+                return true;
+            }
+        }
+    }
+}
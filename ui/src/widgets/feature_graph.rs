@@ -0,0 +1,243 @@
+use std::collections::{HashMap, HashSet};
+
+use egui::{Align2, FontId, Pos2, Rect, RichText, Sense, Stroke, Ui, Vec2};
+
+use crate::backend::{BackendClient, FeatureEntry};
+use crate::theme::Palette;
+use crate::widgets::feature_tree::feature_colour;
+
+/// Cools linearly to zero over this many steps, per the Fruchterman–Reingold
+/// annealing schedule.
+const MAX_ITERATIONS: usize = 150;
+const ITERATIONS_PER_FRAME: usize = 4;
+const EPS: f32 = 0.01;
+const NODE_RADIUS: f32 = 7.0;
+/// A drag has to start within this distance of a node's centre to grab it;
+/// otherwise a drag in empty canvas space would still snap the nearest node
+/// to the cursor.
+const HIT_RADIUS: f32 = NODE_RADIUS * 3.0;
+
+struct Node {
+    pos: Pos2,
+    pinned: bool,
+}
+
+/// Persistent state for the force-directed feature graph view. Node
+/// positions animate into place over a few frames, then settle; dragging a
+/// node pins it (zeroing the force applied to it) so the user can reposition
+/// it by hand.
+///
+/// Nodes are keyed by feature label rather than list index, since the
+/// backend can remove or reorder a feature from the middle of the list —
+/// indexing by position would silently reassign an existing node's position
+/// and pin state to whatever feature now happens to sit at that index.
+pub struct GraphLayout {
+    nodes: HashMap<String, Node>,
+    temperature: f32,
+    iteration: usize,
+    /// Label of the node currently being dragged, latched at
+    /// `drag_started()` so the pointer can wander more than `HIT_RADIUS`
+    /// from the node mid-drag without dropping it.
+    dragging: Option<String>,
+}
+
+impl GraphLayout {
+    pub fn new() -> Self {
+        Self {
+            nodes: HashMap::new(),
+            temperature: 0.0,
+            iteration: 0,
+            dragging: None,
+        }
+    }
+
+    /// Drop nodes for features that disappeared and seed new ones on a
+    /// small circle around the panel's centre — starting all nodes at the
+    /// same point would make every repulsive force cancel out and the
+    /// layout would never separate them.
+    fn sync(&mut self, features: &[FeatureEntry], rect: Rect) {
+        self.nodes
+            .retain(|label, _| features.iter().any(|f| &f.label == label));
+
+        let centre = rect.center();
+        let seed_radius = rect.width().min(rect.height()) * 0.25;
+        let n = features.len().max(1) as f32;
+        for (i, feat) in features.iter().enumerate() {
+            if !self.nodes.contains_key(&feat.label) {
+                let angle = i as f32 * std::f32::consts::TAU / n;
+                self.nodes.insert(
+                    feat.label.clone(),
+                    Node {
+                        pos: centre + seed_radius * Vec2::angled(angle),
+                        pinned: false,
+                    },
+                );
+                // A newly-added node perturbs the existing layout, so
+                // restart the annealing schedule.
+                self.iteration = 0;
+            }
+        }
+    }
+
+    /// Parent and named-reference edges between feature labels.
+    fn edges(features: &[FeatureEntry]) -> Vec<(String, String)> {
+        let labels: HashSet<&str> = features.iter().map(|f| f.label.as_str()).collect();
+
+        let mut edges = Vec::new();
+        for feat in features {
+            if let Some(parent) = feat.parent.as_deref().filter(|p| labels.contains(p)) {
+                edges.push((parent.to_string(), feat.label.clone()));
+            }
+            for reference in &feat.references {
+                if labels.contains(reference.as_str()) {
+                    edges.push((reference.clone(), feat.label.clone()));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Run a handful of Fruchterman–Reingold iterations for this frame.
+    fn step(&mut self, features: &[FeatureEntry], edges: &[(String, String)], rect: Rect) {
+        if self.iteration == 0 {
+            self.temperature = rect.width().min(rect.height()) * 0.1;
+        }
+
+        let area = (rect.width() * rect.height()).max(1.0);
+        let k = (area / features.len().max(1) as f32).sqrt();
+        let labels: Vec<&str> = features.iter().map(|f| f.label.as_str()).collect();
+
+        for _ in 0..ITERATIONS_PER_FRAME {
+            if self.iteration >= MAX_ITERATIONS {
+                break;
+            }
+
+            let mut displacement: HashMap<&str, Vec2> =
+                labels.iter().map(|&label| (label, Vec2::ZERO)).collect();
+
+            // Repulsive force between every pair of nodes.
+            for &a in &labels {
+                for &b in &labels {
+                    if a == b {
+                        continue;
+                    }
+                    let delta = self.nodes[a].pos - self.nodes[b].pos;
+                    let d = delta.length().max(EPS);
+                    let force = k * k / d;
+                    *displacement.get_mut(a).unwrap() += delta / d * force;
+                }
+            }
+
+            // Attractive force along edges, pulling both endpoints together.
+            for (a, b) in edges {
+                let delta = self.nodes[a.as_str()].pos - self.nodes[b.as_str()].pos;
+                let d = delta.length().max(EPS);
+                let force = d * d / k;
+                let pull = delta / d * force;
+                *displacement.get_mut(a.as_str()).unwrap() -= pull;
+                *displacement.get_mut(b.as_str()).unwrap() += pull;
+            }
+
+            let temperature = self.temperature;
+            for &label in &labels {
+                let node = self.nodes.get_mut(label).unwrap();
+                if node.pinned {
+                    continue;
+                }
+                let disp = displacement[label];
+                let len = disp.length().max(EPS);
+                node.pos += disp / len * len.min(temperature);
+                node.pos.x = node.pos.x.clamp(rect.left(), rect.right());
+                node.pos.y = node.pos.y.clamp(rect.top(), rect.bottom());
+            }
+
+            self.iteration += 1;
+            self.temperature =
+                (rect.width().min(rect.height()) * 0.1) * (1.0 - self.iteration as f32 / MAX_ITERATIONS as f32).max(0.0);
+        }
+    }
+
+    fn settled(&self) -> bool {
+        self.iteration >= MAX_ITERATIONS
+    }
+
+    /// Label of the node nearest `pos`, but only if it's within `max_dist` —
+    /// otherwise a drag that starts in empty canvas space would still grab
+    /// and teleport whichever node happens to be closest.
+    fn nearest_within(&self, pos: Pos2, max_dist: f32) -> Option<String> {
+        self.nodes
+            .iter()
+            .map(|(label, node)| (label, node.pos.distance(pos)))
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .filter(|&(_, d)| d <= max_dist)
+            .map(|(label, _)| label.clone())
+    }
+}
+
+/// Render `backend.features` as a force-directed node-link graph instead of
+/// the linear tree list, so parent/child and reference relationships
+/// between sketches, extrudes, patterns, and mirrors are visible at a
+/// glance. Dragging a node pins it in place.
+pub fn render(ui: &mut Ui, backend: &BackendClient, palette: &Palette, layout: &mut GraphLayout) {
+    let features = &backend.features;
+    if features.is_empty() {
+        ui.label(
+            RichText::new("No features yet.")
+                .color(palette.text_muted)
+                .size(13.0),
+        );
+        return;
+    }
+
+    let desired = Vec2::new(ui.available_width(), ui.available_height().max(200.0));
+    let (rect, response) = ui.allocate_exact_size(desired, Sense::click_and_drag());
+
+    layout.sync(features, rect);
+    let edges = GraphLayout::edges(features);
+    layout.step(features, &edges, rect);
+
+    if response.drag_started() {
+        layout.dragging = response
+            .interact_pointer_pos()
+            .and_then(|pos| layout.nearest_within(pos, HIT_RADIUS));
+    }
+    if response.dragged() {
+        if let (Some(label), Some(pos)) = (&layout.dragging, response.interact_pointer_pos()) {
+            if let Some(node) = layout.nodes.get_mut(label) {
+                node.pos = pos;
+                node.pinned = true;
+            }
+        }
+    }
+    if response.drag_released() {
+        layout.dragging = None;
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 4.0, palette.extreme_bg);
+
+    for (a, b) in &edges {
+        painter.line_segment(
+            [layout.nodes[a.as_str()].pos, layout.nodes[b.as_str()].pos],
+            Stroke::new(1.0, palette.text_muted),
+        );
+    }
+
+    for feat in features {
+        let pos = layout.nodes[feat.label.as_str()].pos;
+        let colour = feature_colour(&feat.feature_type, palette);
+        painter.circle_filled(pos, NODE_RADIUS, colour);
+        painter.circle_stroke(pos, NODE_RADIUS, Stroke::new(1.0, palette.extreme_bg));
+        painter.text(
+            pos + Vec2::new(10.0, 0.0),
+            Align2::LEFT_CENTER,
+            &feat.label,
+            FontId::proportional(11.0),
+            palette.text_primary,
+        );
+    }
+
+    if !layout.settled() {
+        ui.ctx().request_repaint();
+    }
+}
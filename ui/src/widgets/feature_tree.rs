@@ -1,14 +1,15 @@
-use egui::{Color32, RichText, ScrollArea, Ui};
+use egui::{Color32, Image, RichText, ScrollArea, Ui};
 
+use crate::assets::Assets;
 use crate::backend::BackendClient;
-use crate::theme;
+use crate::theme::Palette;
 
 /// Render the SolidWorks feature tree panel.
-pub fn render(ui: &mut Ui, backend: &BackendClient) {
+pub fn render(ui: &mut Ui, backend: &BackendClient, assets: &Assets, palette: &Palette) {
     if backend.features.is_empty() {
         ui.label(
             RichText::new("No features yet.")
-                .color(Color32::from_rgb(100, 100, 115))
+                .color(palette.text_muted)
                 .size(13.0),
         );
         return;
@@ -18,8 +19,9 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
         .auto_shrink([false; 2])
         .show(ui, |ui| {
             for (i, feat) in backend.features.iter().enumerate() {
-                let icon = feature_icon(&feat.feature_type);
-                let colour = feature_colour(&feat.feature_type);
+                let icon_name = feature_icon_name(&feat.feature_type);
+                let colour = feature_colour(&feat.feature_type, palette);
+                let line_height = ui.text_style_height(&egui::TextStyle::Body);
 
                 ui.horizontal(|ui| {
                     // Tree indent line
@@ -29,14 +31,25 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
                         } else {
                             "├─"
                         })
-                        .color(Color32::from_rgb(60, 60, 75))
+                        .color(palette.text_muted)
                         .monospace(),
                     );
 
-                    ui.label(RichText::new(icon).color(colour).size(14.0));
+                    match icon_name.and_then(|name| assets.icon(name)) {
+                        Some(texture) => {
+                            ui.add(
+                                Image::new(texture)
+                                    .tint(colour)
+                                    .fit_to_exact_size(egui::vec2(line_height, line_height)),
+                            );
+                        }
+                        None => {
+                            ui.label(RichText::new("●").color(colour).size(14.0));
+                        }
+                    }
                     ui.label(
                         RichText::new(&feat.label)
-                            .color(Color32::from_rgb(210, 210, 225))
+                            .color(palette.text_primary)
                             .size(13.0),
                     );
                 });
@@ -46,7 +59,7 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
                     ui.add_space(32.0);
                     ui.label(
                         RichText::new(&feat.feature_type)
-                            .color(Color32::from_rgb(90, 90, 105))
+                            .color(palette.text_secondary)
                             .size(11.0),
                     );
                 });
@@ -54,31 +67,31 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
         });
 }
 
-fn feature_icon(ftype: &str) -> &'static str {
+/// Name of the bundled icon asset for a feature type (see `Assets`), or
+/// `None` for unrecognised types, which fall back to a plain dot glyph.
+fn feature_icon_name(ftype: &str) -> Option<&str> {
     if ftype.starts_with("sketch") {
-        "□"
+        Some("sketch")
     } else {
         match ftype {
-            "extrude" => "▣",
-            "fillet" => "◠",
-            "chamfer" => "◇",
-            "mirror" => "◫",
-            "linear_pattern" => "⋮⋮",
-            "export" => "↗",
-            _ => "●",
+            "extrude" | "fillet" | "chamfer" | "mirror" | "linear_pattern" | "export" => {
+                Some(ftype)
+            }
+            _ => None,
         }
     }
 }
 
-fn feature_colour(ftype: &str) -> Color32 {
+/// Colour for a feature type, shared with the force-directed graph view.
+pub(crate) fn feature_colour(ftype: &str, palette: &Palette) -> Color32 {
     if ftype.starts_with("sketch") {
-        return theme::ACCENT_BLUE;
+        return palette.accent_blue;
     }
     match ftype {
-        "extrude" => theme::ACCENT_GREEN,
-        "fillet" | "chamfer" => theme::ACCENT_AMBER,
-        "mirror" | "linear_pattern" => theme::ACCENT_PURPLE,
-        "export" => Color32::from_rgb(180, 180, 195),
-        _ => Color32::from_rgb(150, 150, 165),
+        "extrude" => palette.accent_green,
+        "fillet" | "chamfer" => palette.accent_amber,
+        "mirror" | "linear_pattern" => palette.accent_purple,
+        "export" => palette.text_primary,
+        _ => palette.text_secondary,
     }
 }
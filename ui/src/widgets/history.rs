@@ -1,15 +1,15 @@
 use egui::{Color32, RichText, ScrollArea, Ui};
 
 use crate::backend::BackendClient;
-use crate::theme;
+use crate::theme::Palette;
 
 /// Render the scrollable command history panel.
-pub fn render(ui: &mut Ui, backend: &BackendClient) {
+pub fn render(ui: &mut Ui, backend: &BackendClient, palette: &Palette) {
     if backend.history.is_empty() {
         ui.centered_and_justified(|ui| {
             ui.label(
                 RichText::new("No commands yet — speak or type a command to get started.")
-                    .color(Color32::from_rgb(100, 100, 115))
+                    .color(palette.text_muted)
                     .size(14.0),
             );
         });
@@ -24,25 +24,24 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
                     ui.horizontal(|ui| {
                         ui.label(
                             RichText::new(&entry.timestamp)
-                                .color(Color32::from_rgb(100, 100, 115))
+                                .color(palette.text_muted)
                                 .monospace()
                                 .size(11.0),
                         );
                         ui.label(
                             RichText::new(&entry.action)
-                                .color(action_colour(&entry.action))
+                                .color(action_colour(&entry.action, palette))
                                 .strong()
                                 .size(13.0),
                         );
                     });
                     ui.label(
-                        RichText::new(format!("> {}", entry.command))
-                            .color(Color32::from_rgb(200, 200, 215)),
+                        RichText::new(format!("> {}", entry.command)).color(palette.text_primary),
                     );
                     if !entry.result.is_empty() {
                         ui.label(
                             RichText::new(&entry.result)
-                                .color(Color32::from_rgb(140, 140, 160))
+                                .color(palette.text_secondary)
                                 .size(12.0),
                         );
                     }
@@ -52,13 +51,13 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
         });
 }
 
-fn action_colour(action: &str) -> Color32 {
+fn action_colour(action: &str, palette: &Palette) -> Color32 {
     match action {
-        "sketch" => theme::ACCENT_BLUE,
-        "extrude" => theme::ACCENT_GREEN,
-        "fillet" | "chamfer" => theme::ACCENT_AMBER,
-        "mirror" | "pattern" => theme::ACCENT_PURPLE,
-        "error" => theme::ACCENT_RED,
-        _ => Color32::from_rgb(180, 180, 195),
+        "sketch" => palette.accent_blue,
+        "extrude" => palette.accent_green,
+        "fillet" | "chamfer" => palette.accent_amber,
+        "mirror" | "pattern" => palette.accent_purple,
+        "error" => palette.accent_red,
+        _ => palette.text_primary,
     }
 }
@@ -1,21 +1,21 @@
-use egui::{Color32, RichText, Ui};
+use egui::{RichText, Ui};
 
 use crate::backend::BackendClient;
-use crate::theme;
+use crate::theme::Palette;
 
 /// Render connection-status indicator chips in the top bar.
-pub fn render(ui: &mut Ui, backend: &BackendClient) {
-    indicator(ui, "SolidWorks", backend.solidworks_ok);
-    indicator(ui, "Qdrant", backend.qdrant_ok);
-    indicator(ui, "Ollama", backend.ollama_ok);
-    indicator(ui, "Claude", backend.claude_ok);
+pub fn render(ui: &mut Ui, backend: &BackendClient, palette: &Palette) {
+    indicator(ui, "SolidWorks", backend.solidworks_ok, palette);
+    indicator(ui, "Qdrant", backend.qdrant_ok, palette);
+    indicator(ui, "Ollama", backend.ollama_ok, palette);
+    indicator(ui, "Claude", backend.claude_ok, palette);
 }
 
-fn indicator(ui: &mut Ui, label: &str, ok: bool) {
+fn indicator(ui: &mut Ui, label: &str, ok: bool, palette: &Palette) {
     let (colour, symbol) = if ok {
-        (theme::STATUS_OK, "●")
+        (palette.status_ok(), "●")
     } else {
-        (theme::STATUS_ERR, "○")
+        (palette.status_err(), "○")
     };
 
     ui.horizontal(|ui| {
@@ -23,9 +23,9 @@ fn indicator(ui: &mut Ui, label: &str, ok: bool) {
         ui.label(
             RichText::new(label)
                 .color(if ok {
-                    Color32::from_rgb(200, 200, 215)
+                    palette.text_primary
                 } else {
-                    Color32::from_rgb(120, 120, 135)
+                    palette.text_muted
                 })
                 .size(12.0),
         );
@@ -1,17 +1,17 @@
-use egui::{Color32, Rect, RichText, Sense, Ui, Vec2};
+use egui::{Rect, RichText, Sense, Ui, Vec2};
 
 use crate::backend::BackendClient;
-use crate::theme;
+use crate::theme::Palette;
 
 /// Render the voice input panel with a waveform visualisation.
-pub fn render(ui: &mut Ui, backend: &BackendClient) {
+pub fn render(ui: &mut Ui, backend: &BackendClient, palette: &Palette) {
     let listening = backend.is_listening();
 
     ui.horizontal(|ui| {
         let mic_colour = if listening {
-            theme::ACCENT_RED
+            palette.accent_red
         } else {
-            theme::ACCENT_BLUE
+            palette.accent_blue
         };
         ui.label(
             RichText::new(if listening { "Recording…" } else { "Voice Input" })
@@ -23,7 +23,7 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
             ui.separator();
             ui.label(
                 RichText::new(format!("\"{}\"", backend.last_transcription))
-                    .color(Color32::from_rgb(180, 180, 195))
+                    .color(palette.text_primary)
                     .italics(),
             );
         }
@@ -36,7 +36,7 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
     let painter = ui.painter_at(rect);
 
     // Background
-    painter.rect_filled(rect, 4.0, Color32::from_rgb(12, 12, 16));
+    painter.rect_filled(rect, 4.0, palette.extreme_bg);
 
     let samples = if backend.waveform.is_empty() {
         // Draw a flat line when idle
@@ -51,9 +51,9 @@ pub fn render(ui: &mut Ui, backend: &BackendClient) {
     let max_h = rect.height() * 0.45;
 
     let bar_colour = if listening {
-        theme::ACCENT_RED.linear_multiply(0.8)
+        palette.accent_red.linear_multiply(0.8)
     } else {
-        theme::ACCENT_BLUE.linear_multiply(0.4)
+        palette.accent_blue.linear_multiply(0.4)
     };
 
     for (i, &sample) in samples.iter().enumerate() {